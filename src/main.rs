@@ -1,22 +1,103 @@
-use std::{fs::File, io::Write};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    process::ExitCode,
+};
+
+mod utils;
 
 fn show_info() {
     eprintln!("MUSI-6106 Assignment Executable");
     eprintln!("(c) 2024 Stephen Garrett & Ian Clester");
 }
 
-fn main() {
-   show_info();
+/// Reads `input_path` and writes its samples to `output_path` as text, one column per channel.
+fn run(input_path: &str, output_path: &str) -> Result<(), hound::Error> {
+    // Open the input wave file and determine number of channels.
+    // Any integer or float bit depth is converted to floating point in [-1, 1].
+    let (samples, spec) = utils::read_wav_as_f32(input_path)?;
+    let channels = spec.channels as usize;
+
+    // Read audio data and write it to the output text file (one column per channel)
+    let mut out = BufWriter::new(File::create(output_path)?);
+    for frame in samples.chunks(channels) {
+        let line: Vec<String> = frame.iter().map(|s| s.to_string()).collect();
+        writeln!(out, "{}", line.join(" "))?;
+    }
+    // Flush explicitly so write errors are reported instead of being lost on drop.
+    out.flush()?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    show_info();
 
     // Parse command line arguments
     // First argument is input .wav file, second argument is output text file.
     let args: Vec<String> = std::env::args().collect();
-    // TODO: your code here
+    if args.len() < 3 {
+        let program = args.first().map_or("ase", String::as_str);
+        eprintln!(
+            "Usage: {} <input wave filename> <output text filename>",
+            program
+        );
+        return ExitCode::FAILURE;
+    }
 
-    // Open the input wave file and determine number of channels
-    // TODO: your code here; see `hound::WavReader::open`.
+    match run(&args[1], &args[2]) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    // Read audio data and write it to the output text file (one column per channel)
-    // TODO: your code here; we suggest using `hound::WavReader::samples`, `File::create`, and `write!`.
-    //       Remember to convert the samples to floating point values and respect the number of channels!
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_writes_one_column_per_channel() {
+        let dir = std::env::temp_dir();
+        let input_path = dir.join(format!("ase_main_in_{}.wav", std::process::id()));
+        let output_path = dir.join(format!("ase_main_out_{}.txt", std::process::id()));
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let input = [16384i16, -16384, 8192, 0, -32768, 24576];
+        let mut writer = hound::WavWriter::create(&input_path, spec).unwrap();
+        for s in input {
+            writer.write_sample(s).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        run(input_path.to_str().unwrap(), output_path.to_str().unwrap()).unwrap();
+        let text = std::fs::read_to_string(&output_path).unwrap();
+        std::fs::remove_file(&input_path).unwrap();
+        std::fs::remove_file(&output_path).unwrap();
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), input.len() / 2);
+        for (line, frame) in lines.iter().zip(input.chunks(2)) {
+            let values: Vec<f32> = line
+                .split_whitespace()
+                .map(|v| v.parse().unwrap())
+                .collect();
+            let expected: Vec<f32> = frame.iter().map(|&s| s as f32 / 32768.0).collect();
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    fn test_run_missing_input_returns_error() {
+        let dir = std::env::temp_dir();
+        let input_path = dir.join("ase_main_does_not_exist.wav");
+        let output_path = dir.join(format!("ase_main_unused_{}.txt", std::process::id()));
+        assert!(run(input_path.to_str().unwrap(), output_path.to_str().unwrap()).is_err());
+        assert!(!output_path.exists());
+    }
 }
@@ -0,0 +1,108 @@
+use hound::{SampleFormat, WavReader, WavSpec};
+
+/// Reads a WAV file of any supported bit depth (integer 8/16/24/32 or 32-bit float)
+/// and returns its interleaved samples as `f32` in `[-1, 1]` along with the file's spec.
+pub fn read_wav_as_f32(path: &str) -> Result<(Vec<f32>, WavSpec), hound::Error> {
+    let mut reader = WavReader::open(path)?;
+    let spec = reader.spec();
+    let samples = match spec.sample_format {
+        SampleFormat::Float => reader.samples::<f32>().collect::<Result<Vec<_>, _>>()?,
+        SampleFormat::Int => {
+            // Integer samples are signed with full scale at 2^(bits - 1).
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|s| s as f32 / scale))
+                .collect::<Result<Vec<_>, _>>()?
+        }
+    };
+    Ok((samples, spec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hound::WavWriter;
+    use std::path::PathBuf;
+
+    fn temp_wav(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ase_utils_{}_{}.wav", name, std::process::id()))
+    }
+
+    fn int_spec(channels: u16, bits_per_sample: u16) -> WavSpec {
+        WavSpec {
+            channels,
+            sample_rate: 44100,
+            bits_per_sample,
+            sample_format: SampleFormat::Int,
+        }
+    }
+
+    #[test]
+    fn test_read_24_bit() {
+        let path = temp_wav("24_bit");
+        let mut writer = WavWriter::create(&path, int_spec(1, 24)).unwrap();
+        for s in [0, 4194304, -8388608, 8388607] {
+            writer.write_sample(s).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let (samples, spec) = read_wav_as_f32(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(spec.bits_per_sample, 24);
+        assert_eq!(&samples[..3], &[0.0, 0.5, -1.0]);
+        assert!((samples[3] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_read_8_bit() {
+        // hound stores 8-bit samples unsigned on disk but reads them back as signed.
+        let path = temp_wav("8_bit");
+        let mut writer = WavWriter::create(&path, int_spec(1, 8)).unwrap();
+        for s in [0i8, 64, -128, 127] {
+            writer.write_sample(s).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let (samples, _) = read_wav_as_f32(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(samples, vec![0.0, 0.5, -1.0, 127.0 / 128.0]);
+    }
+
+    #[test]
+    fn test_read_32_bit_float() {
+        let path = temp_wav("32_bit_float");
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+        let input = [0.5f32, -0.25, 1.0, -1.0, 0.123456];
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for s in input {
+            writer.write_sample(s).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let (samples, spec) = read_wav_as_f32(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(spec.sample_format, SampleFormat::Float);
+        assert_eq!(samples, input.to_vec());
+    }
+
+    #[test]
+    fn test_read_stereo_keeps_interleaving() {
+        let path = temp_wav("stereo");
+        let mut writer = WavWriter::create(&path, int_spec(2, 16)).unwrap();
+        for s in [16384i16, -16384, 0, -32768] {
+            writer.write_sample(s).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let (samples, spec) = read_wav_as_f32(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(spec.channels, 2);
+        assert_eq!(samples, vec![0.5, -0.5, 0.0, -1.0]);
+    }
+}